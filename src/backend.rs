@@ -0,0 +1,144 @@
+//! Abstraction over the concrete token-stream implementation used to build a diagnostic.
+//!
+//! The diagnostic hack (an unimplemented-trait block for [`crate::Error`], a `#[deprecated]` item
+//! for [`crate::Warning`]) only needs a handful of primitive tokens: identifiers, punctuation,
+//! string literals, and delimited groups, each optionally carrying a span. [`Backend`] captures
+//! just that surface, so the same construction logic can run against either `proc_macro` (the
+//! real compiler) or, when the `proc-macro2` feature is enabled and no real compiler is attached,
+//! `proc_macro2`'s fallback implementation — which is also what lets the construction run from
+//! ordinary `#[test]`s.
+
+/// A delimiter for a [`Backend::group`], independent of which concrete token-stream crate is in
+/// use.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Delimiter {
+    Parenthesis,
+    Brace,
+    Bracket,
+}
+
+/// The token constructors needed to build a diagnostic, abstracted over the concrete token-stream
+/// implementation.
+pub(crate) trait Backend {
+    type Span: Copy;
+    type TokenStream: Extend<Self::TokenStream> + FromIterator<Self::TokenStream> + Default;
+
+    fn call_site() -> Self::Span;
+    fn empty() -> Self::TokenStream {
+        Self::TokenStream::default()
+    }
+    fn concat(streams: impl IntoIterator<Item = Self::TokenStream>) -> Self::TokenStream {
+        Self::TokenStream::from_iter(streams)
+    }
+    fn ident(name: &str, span: Self::Span) -> Self::TokenStream;
+    fn punct(ch: char, span: Self::Span) -> Self::TokenStream;
+    fn literal_string(value: &str, span: Self::Span) -> Self::TokenStream;
+    fn group(delimiter: Delimiter, inner: Self::TokenStream, span: Self::Span)
+        -> Self::TokenStream;
+}
+
+/// The real `proc_macro` compiler API. Used whenever `proc_macro::is_available()` is `true`.
+pub(crate) struct Native;
+
+impl Backend for Native {
+    type Span = proc_macro::Span;
+    type TokenStream = proc_macro::TokenStream;
+
+    fn call_site() -> Self::Span {
+        proc_macro::Span::call_site()
+    }
+
+    fn ident(name: &str, span: Self::Span) -> Self::TokenStream {
+        proc_macro::TokenStream::from(proc_macro::TokenTree::from(proc_macro::Ident::new(
+            name, span,
+        )))
+    }
+
+    fn punct(ch: char, span: Self::Span) -> Self::TokenStream {
+        let mut punct = proc_macro::Punct::new(ch, proc_macro::Spacing::Joint);
+        punct.set_span(span);
+        proc_macro::TokenStream::from(proc_macro::TokenTree::from(punct))
+    }
+
+    fn literal_string(value: &str, span: Self::Span) -> Self::TokenStream {
+        let mut literal = proc_macro::Literal::string(value);
+        literal.set_span(span);
+        proc_macro::TokenStream::from(proc_macro::TokenTree::from(literal))
+    }
+
+    fn group(
+        delimiter: Delimiter,
+        inner: Self::TokenStream,
+        span: Self::Span,
+    ) -> Self::TokenStream {
+        let mut group = proc_macro::Group::new(delimiter.into(), inner);
+        group.set_span(span);
+        proc_macro::TokenStream::from(proc_macro::TokenTree::from(group))
+    }
+}
+
+impl From<Delimiter> for proc_macro::Delimiter {
+    fn from(delimiter: Delimiter) -> Self {
+        match delimiter {
+            Delimiter::Parenthesis => proc_macro::Delimiter::Parenthesis,
+            Delimiter::Brace => proc_macro::Delimiter::Brace,
+            Delimiter::Bracket => proc_macro::Delimiter::Bracket,
+        }
+    }
+}
+
+/// The `proc_macro2` fallback API. Used instead of [`Native`] when the `proc-macro2` feature is
+/// enabled and there is no real compiler attached (build scripts, unit tests, or any other
+/// non-proc-macro context), so that diagnostics can still be constructed with the caller's
+/// original spans rather than silently falling back to [`call_site`](Backend::call_site).
+#[cfg(feature = "proc-macro2")]
+pub(crate) struct ProcMacro2;
+
+#[cfg(feature = "proc-macro2")]
+impl Backend for ProcMacro2 {
+    type Span = proc_macro2::Span;
+    type TokenStream = proc_macro2::TokenStream;
+
+    fn call_site() -> Self::Span {
+        proc_macro2::Span::call_site()
+    }
+
+    fn ident(name: &str, span: Self::Span) -> Self::TokenStream {
+        proc_macro2::TokenStream::from(proc_macro2::TokenTree::from(proc_macro2::Ident::new(
+            name, span,
+        )))
+    }
+
+    fn punct(ch: char, span: Self::Span) -> Self::TokenStream {
+        let mut punct = proc_macro2::Punct::new(ch, proc_macro2::Spacing::Joint);
+        punct.set_span(span);
+        proc_macro2::TokenStream::from(proc_macro2::TokenTree::from(punct))
+    }
+
+    fn literal_string(value: &str, span: Self::Span) -> Self::TokenStream {
+        let mut literal = proc_macro2::Literal::string(value);
+        literal.set_span(span);
+        proc_macro2::TokenStream::from(proc_macro2::TokenTree::from(literal))
+    }
+
+    fn group(
+        delimiter: Delimiter,
+        inner: Self::TokenStream,
+        span: Self::Span,
+    ) -> Self::TokenStream {
+        let mut group = proc_macro2::Group::new(delimiter.into(), inner);
+        group.set_span(span);
+        proc_macro2::TokenStream::from(proc_macro2::TokenTree::from(group))
+    }
+}
+
+#[cfg(feature = "proc-macro2")]
+impl From<Delimiter> for proc_macro2::Delimiter {
+    fn from(delimiter: Delimiter) -> Self {
+        match delimiter {
+            Delimiter::Parenthesis => proc_macro2::Delimiter::Parenthesis,
+            Delimiter::Brace => proc_macro2::Delimiter::Brace,
+            Delimiter::Bracket => proc_macro2::Delimiter::Bracket,
+        }
+    }
+}
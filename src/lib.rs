@@ -3,19 +3,11 @@
 
 extern crate proc_macro;
 
-use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+mod backend;
 
-macro_rules! tts {
-    ($($item:expr),* $(,)?) => {
-        [$(TokenTree::from($item)),*]
-    };
-}
+use proc_macro::{Span, TokenStream};
 
-macro_rules! punct {
-    ($token:tt) => {
-        Punct::new($token, Spacing::Joint)
-    };
-}
+use crate::backend::Backend;
 
 #[derive(Debug, Clone, Copy)]
 enum SpanPair {
@@ -23,7 +15,10 @@ enum SpanPair {
         start: proc_macro::Span,
         end: proc_macro::Span,
     },
-    #[cfg(feature = "proc-macro2")]
+    // Also used, independent of the `proc-macro2` feature, by the `syn` conversions below: a
+    // `syn::Error`'s span is always a `proc_macro2::Span`, so `syn` alone must be able to
+    // construct this variant without also requiring `proc-macro2`.
+    #[cfg(any(feature = "proc-macro2", feature = "syn"))]
     ProcMacro2 {
         start: proc_macro2::Span,
         end: proc_macro2::Span,
@@ -34,7 +29,7 @@ impl SpanPair {
     fn start_native(&self) -> Option<proc_macro::Span> {
         match *self {
             Self::Native { start, .. } => Some(start),
-            #[cfg(feature = "proc-macro2")]
+            #[cfg(any(feature = "proc-macro2", feature = "syn"))]
             Self::ProcMacro2 { start, .. } => proc_macro::is_available().then(|| start.unwrap()),
         }
     }
@@ -42,10 +37,35 @@ impl SpanPair {
     fn end_native(&self) -> Option<proc_macro::Span> {
         match *self {
             Self::Native { end, .. } => Some(end),
-            #[cfg(feature = "proc-macro2")]
+            #[cfg(any(feature = "proc-macro2", feature = "syn"))]
             Self::ProcMacro2 { end, .. } => proc_macro::is_available().then(|| end.unwrap()),
         }
     }
+
+    /// The start span, rendered with the `proc-macro2` fallback backend.
+    ///
+    /// Unlike [`start_native`](Self::start_native), this never needs a real compiler: a
+    /// [`Self::ProcMacro2`] span is already a `proc_macro2::Span` and is returned as-is. A
+    /// [`Self::Native`] span can only exist by way of a real `proc_macro::Span`, which requires a
+    /// real compiler to have been constructed in the first place, so this falls back to
+    /// `call_site` there; that combination shouldn't arise in practice.
+    #[cfg(feature = "proc-macro2")]
+    fn start_proc_macro2(&self) -> proc_macro2::Span {
+        match *self {
+            Self::Native { .. } => proc_macro2::Span::call_site(),
+            Self::ProcMacro2 { start, .. } => start,
+        }
+    }
+
+    /// The end span, rendered with the `proc-macro2` fallback backend. See
+    /// [`start_proc_macro2`](Self::start_proc_macro2).
+    #[cfg(feature = "proc-macro2")]
+    fn end_proc_macro2(&self) -> proc_macro2::Span {
+        match *self {
+            Self::Native { .. } => proc_macro2::Span::call_site(),
+            Self::ProcMacro2 { end, .. } => end,
+        }
+    }
 }
 
 impl From<proc_macro::Span> for SpanPair {
@@ -66,7 +86,7 @@ impl From<(proc_macro::Span, proc_macro::Span)> for SpanPair {
     }
 }
 
-#[cfg(feature = "proc-macro2")]
+#[cfg(any(feature = "proc-macro2", feature = "syn"))]
 impl From<proc_macro2::Span> for SpanPair {
     fn from(span: proc_macro2::Span) -> Self {
         SpanPair::ProcMacro2 {
@@ -76,7 +96,7 @@ impl From<proc_macro2::Span> for SpanPair {
     }
 }
 
-#[cfg(feature = "proc-macro2")]
+#[cfg(any(feature = "proc-macro2", feature = "syn"))]
 impl From<(proc_macro2::Span, proc_macro2::Span)> for SpanPair {
     fn from(spans: (proc_macro2::Span, proc_macro2::Span)) -> Self {
         SpanPair::ProcMacro2 {
@@ -96,6 +116,7 @@ pub struct Error {
     label: Option<Box<str>>,
     notes: Vec<Box<str>>,
     span: Option<SpanPair>,
+    secondary: Vec<(SpanPair, Box<str>)>,
 }
 
 impl Error {
@@ -106,6 +127,7 @@ impl Error {
             label: None,
             notes: Vec::new(),
             span: None,
+            secondary: Vec::new(),
         }
     }
 
@@ -137,6 +159,18 @@ impl Error {
         self
     }
 
+    /// Attach a secondary labeled span to the error, in addition to its primary span.
+    ///
+    /// Unlike [`label`](Self::label), which annotates the primary span, `span_note` underlines
+    /// an independent location with its own message (e.g. "first defined here"). This method may
+    /// be called multiple times to attach multiple secondary spans.
+    #[allow(private_bounds)] // deliberately not exposing inner type
+    pub fn span_note(mut self, span: impl Into<SpanPair>, message: impl ToString) -> Self {
+        self.secondary
+            .push((span.into(), message.to_string().into_boxed_str()));
+        self
+    }
+
     /// Append the error to the provided `TokenStream`.
     pub fn to_tokens(&self, tokens: &mut TokenStream) {
         let call_site = Span::call_site();
@@ -149,119 +183,525 @@ impl Error {
             .and_then(|pair| pair.end_native())
             .unwrap_or(call_site);
 
-        macro_rules! ident {
-            ($name:ident) => {
-                Ident::new(stringify!($name), call_site)
-            };
+        tokens.extend(diagnostic_block::<backend::Native>(
+            &self.message,
+            self.label.as_deref(),
+            &self.notes,
+            start_span,
+            end_span,
+        ));
+
+        for (span, message) in &self.secondary {
+            let start_span = span.start_native().unwrap_or(call_site);
+            let end_span = span.end_native().unwrap_or(call_site);
+            tokens.extend(diagnostic_block::<backend::Native>(
+                message,
+                None,
+                &[],
+                start_span,
+                end_span,
+            ));
         }
+    }
 
-        let customization = {
-            let mut ts = TokenStream::from_iter(tts![
-                ident!(message),
-                punct!('='),
-                Literal::string(&self.message),
-            ]);
-            if let Some(label) = &self.label {
-                ts.extend(tts![
-                    punct!(','),
-                    ident!(label),
-                    punct!('='),
-                    Literal::string(label),
-                ]);
-            }
-            for note in &self.notes {
-                ts.extend(tts![
-                    punct!(','),
-                    ident!(note),
-                    punct!('='),
-                    Literal::string(note),
-                ]);
-            }
-            ts
-        };
-
-        let mut inner_ts = TokenStream::from_iter(tts![
-            punct!('#'),
-            Group::new(
-                Delimiter::Bracket,
-                TokenStream::from_iter(tts![
-                    ident!(diagnostic),
-                    punct!(':'),
-                    punct!(':'),
-                    ident!(on_unimplemented),
-                    Group::new(Delimiter::Parenthesis, customization),
-                ]),
-            ),
-            ident!(trait),
-            ident!(DiagnosticHack),
-            Group::new(Delimiter::Brace, TokenStream::new()),
+    /// Render the error as a `proc_macro2::TokenStream`, using the `proc-macro2` fallback backend
+    /// so the original spans are preserved even when no real compiler is attached.
+    ///
+    /// Unlike [`to_tokens`](Self::to_tokens), which is built on `proc_macro` and can only run
+    /// inside a real proc-macro invocation, this can be called from ordinary `#[test]`s and other
+    /// non-proc-macro contexts. The [`quote::ToTokens`] impl (under the `quote` feature) calls
+    /// through to this when `proc_macro::is_available()` is `false`.
+    #[cfg(feature = "proc-macro2")]
+    pub fn to_proc_macro2_tokens(&self) -> proc_macro2::TokenStream {
+        let call_site = proc_macro2::Span::call_site();
+        let start_span = self.span.map_or(call_site, |pair| pair.start_proc_macro2());
+        let end_span = self.span.map_or(call_site, |pair| pair.end_proc_macro2());
+
+        let mut ts = diagnostic_block::<backend::ProcMacro2>(
+            &self.message,
+            self.label.as_deref(),
+            &self.notes,
+            start_span,
+            end_span,
+        );
+
+        for (span, message) in &self.secondary {
+            ts.extend(diagnostic_block::<backend::ProcMacro2>(
+                message,
+                None,
+                &[],
+                span.start_proc_macro2(),
+                span.end_proc_macro2(),
+            ));
+        }
+
+        ts
+    }
+}
+
+#[cfg(feature = "quote")]
+impl quote::ToTokens for Error {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        #[cfg(feature = "proc-macro2")]
+        if !proc_macro::is_available() {
+            tokens.extend(self.to_proc_macro2_tokens());
+            return;
+        }
+
+        let mut ts = TokenStream::new();
+        self.to_tokens(&mut ts);
+        tokens.extend(proc_macro2::TokenStream::from(ts));
+    }
+}
+
+/// Build one `{ #[diagnostic::on_unimplemented(...)] trait DiagnosticHack { ... } ... }` block,
+/// spanned at `start_span..end_span`, for [`Error::to_tokens`]. Called once for the primary
+/// diagnostic and once per [`Error::span_note`] entry; each call lives in its own brace scope, so
+/// the repeated `DiagnosticHack` name never collides between blocks.
+///
+/// Generic over the token-stream [`Backend`] so the same construction logic renders through
+/// `proc_macro` (the default) or, on the `proc-macro2` fallback path, `proc_macro2`.
+fn diagnostic_block<B: Backend>(
+    message: &str,
+    label: Option<&str>,
+    notes: &[Box<str>],
+    start_span: B::Span,
+    end_span: B::Span,
+) -> B::TokenStream {
+    use backend::Delimiter;
+
+    let call_site = B::call_site();
+
+    let mut customization = B::concat([
+        B::ident("message", call_site),
+        B::punct('=', call_site),
+        B::literal_string(message, call_site),
+    ]);
+    if let Some(label) = label {
+        customization = B::concat([
+            customization,
+            B::punct(',', call_site),
+            B::ident("label", call_site),
+            B::punct('=', call_site),
+            B::literal_string(label, call_site),
+        ]);
+    }
+    for note in notes {
+        customization = B::concat([
+            customization,
+            B::punct(',', call_site),
+            B::ident("note", call_site),
+            B::punct('=', call_site),
+            B::literal_string(note, call_site),
         ]);
-        #[cfg(not(feature = "msrv-1-78"))]
-        inner_ts.extend(tts![
-            punct!('#'),
-            Group::new(
+    }
+
+    let trait_block = B::concat([
+        B::punct('#', call_site),
+        B::group(
+            Delimiter::Bracket,
+            B::concat([
+                B::ident("diagnostic", call_site),
+                B::punct(':', call_site),
+                B::punct(':', call_site),
+                B::ident("on_unimplemented", call_site),
+                B::group(Delimiter::Parenthesis, customization, call_site),
+            ]),
+            call_site,
+        ),
+        B::ident("trait", call_site),
+        B::ident("DiagnosticHack", call_site),
+        B::group(Delimiter::Brace, B::empty(), call_site),
+    ]);
+
+    // `#[diagnostic::do_not_recommend]` was stabilized after the 1.78 MSRV this crate otherwise
+    // targets; `cfg!` (rather than `#[cfg]`) keeps both arms type-checked under every feature
+    // combination, so there's no conditionally-unused local to trip `-D warnings` over.
+    let do_not_recommend = if cfg!(feature = "msrv-1-78") {
+        B::empty()
+    } else {
+        B::concat([
+            B::punct('#', call_site),
+            B::group(
                 Delimiter::Bracket,
-                TokenStream::from_iter(tts![
-                    ident!(diagnostic),
-                    punct!(':'),
-                    punct!(':'),
-                    ident!(do_not_recommend),
+                B::concat([
+                    B::ident("diagnostic", call_site),
+                    B::punct(':', call_site),
+                    B::punct(':', call_site),
+                    B::ident("do_not_recommend", call_site),
                 ]),
+                call_site,
             ),
-        ]);
+        ])
+    };
 
-        inner_ts.extend(tts![
-            ident!(impl),
-            ident!(DiagnosticHack),
-            ident!(for),
-            punct!(':'),
-            punct!(':'),
-            ident!(core),
-            punct!(':'),
-            punct!(':'),
-            ident!(convert),
-            punct!(':'),
-            punct!(':'),
-            ident!(Infallible),
-            Group::new(Delimiter::Brace, TokenStream::new()),
-            ident!(fn),
-            ident!(diagnostic_hack),
-            punct!('<'),
-            ident!(T),
-            punct!(':'),
-            ident!(DiagnosticHack),
-            punct!('>'),
-            Group::new(Delimiter::Parenthesis, TokenStream::new()),
-            Group::new(Delimiter::Brace, TokenStream::new()),
-            ident!(diagnostic_hack),
-            punct!(':'),
-            punct!(':'),
-            punct!('<'),
-            {
-                let mut tt = punct!('*');
-                tt.set_span(start_span);
-                tt
-            },
-            ident!(const),
-            {
-                let mut tt = Group::new(Delimiter::Parenthesis, TokenStream::new());
-                tt.set_span(end_span);
-                tt
-            },
-            punct!('>'),
-            Group::new(Delimiter::Parenthesis, TokenStream::new()),
-            punct!(';'),
-        ]);
+    let inner = B::concat([trait_block, do_not_recommend]);
+
+    B::group(
+        Delimiter::Brace,
+        B::concat([
+            inner,
+            B::ident("impl", call_site),
+            B::ident("DiagnosticHack", call_site),
+            B::ident("for", call_site),
+            B::punct(':', call_site),
+            B::punct(':', call_site),
+            B::ident("core", call_site),
+            B::punct(':', call_site),
+            B::punct(':', call_site),
+            B::ident("convert", call_site),
+            B::punct(':', call_site),
+            B::punct(':', call_site),
+            B::ident("Infallible", call_site),
+            B::group(Delimiter::Brace, B::empty(), call_site),
+            B::ident("fn", call_site),
+            B::ident("diagnostic_hack", call_site),
+            B::punct('<', call_site),
+            B::ident("T", call_site),
+            B::punct(':', call_site),
+            B::ident("DiagnosticHack", call_site),
+            B::punct('>', call_site),
+            B::group(Delimiter::Parenthesis, B::empty(), call_site),
+            B::group(Delimiter::Brace, B::empty(), call_site),
+            B::ident("diagnostic_hack", call_site),
+            B::punct(':', call_site),
+            B::punct(':', call_site),
+            B::punct('<', call_site),
+            B::punct('*', start_span),
+            B::ident("const", call_site),
+            B::group(Delimiter::Parenthesis, B::empty(), end_span),
+            B::punct('>', call_site),
+            B::group(Delimiter::Parenthesis, B::empty(), call_site),
+            B::punct(';', call_site),
+        ]),
+        call_site,
+    )
+}
+
+/// A structure representing a warning message.
+///
+/// Unlike [`Error`], a `Warning` does not abort compilation. Rather than the
+/// unimplemented-trait `on_unimplemented` hack, it emits a private `#[deprecated]` item and a
+/// use of that item at the given span, so the `deprecated` lint fires as a non-fatal warning
+/// carrying the message. Because the `deprecated` lint reports a single location rather than an
+/// underlined range, and has no concept of labels or notes, `Warning` does not support
+/// [`Error::label`] or [`Error::note`] — only a top-level message and a span.
+///
+/// **Note**: The output of this structure is only valid in expression position.
+#[must_use = "this struct does nothing unless explicitly appended to a `TokenStream`"]
+#[derive(Debug, Clone)]
+pub struct Warning {
+    message: Box<str>,
+    span: Option<SpanPair>,
+}
 
-        tokens.extend(tts![Group::new(Delimiter::Brace, inner_ts)]);
+impl Warning {
+    /// Create a new `Warning` with the given message.
+    pub fn new(message: impl ToString) -> Self {
+        Self {
+            message: message.to_string().into_boxed_str(),
+            span: None,
+        }
+    }
+
+    /// Set the span of the warning.
+    ///
+    /// This method accepts a [`proc_macro::Span`], [`proc_macro2::Span`], `(proc_macro::Span,
+    /// proc_macro::Span)`, or `(proc_macro2::Span, proc_macro2::Span)`. Note that the
+    /// `proc-macro2` or `syn` feature must be enabled to pass a `proc_macro2::Span`. Unlike
+    /// [`Error::span`], only the start of the span is used to locate the warning.
+    ///
+    /// If this method is called multiple times, the final call takes precedence.
+    #[allow(private_bounds)] // deliberately not exposing inner type
+    pub fn span(mut self, span: impl Into<SpanPair>) -> Self {
+        self.span = Some(span.into());
+        self
+    }
+
+    /// Append the warning to the provided `TokenStream`.
+    pub fn to_tokens(&self, tokens: &mut TokenStream) {
+        let call_site = Span::call_site();
+        let start_span = self
+            .span
+            .and_then(|pair| pair.start_native())
+            .unwrap_or(call_site);
+
+        tokens.extend(deprecated_block::<backend::Native>(
+            &self.message,
+            start_span,
+        ));
+    }
+
+    /// Render the warning as a `proc_macro2::TokenStream`, using the `proc-macro2` fallback
+    /// backend so the original span is preserved even when no real compiler is attached.
+    ///
+    /// Unlike [`to_tokens`](Self::to_tokens), which is built on `proc_macro` and can only run
+    /// inside a real proc-macro invocation, this can be called from ordinary `#[test]`s and other
+    /// non-proc-macro contexts. The [`quote::ToTokens`] impl (under the `quote` feature) calls
+    /// through to this when `proc_macro::is_available()` is `false`.
+    #[cfg(feature = "proc-macro2")]
+    pub fn to_proc_macro2_tokens(&self) -> proc_macro2::TokenStream {
+        let call_site = proc_macro2::Span::call_site();
+        let start_span = self.span.map_or(call_site, |pair| pair.start_proc_macro2());
+
+        deprecated_block::<backend::ProcMacro2>(&self.message, start_span)
     }
 }
 
 #[cfg(feature = "quote")]
-impl quote::ToTokens for Error {
+impl quote::ToTokens for Warning {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        #[cfg(feature = "proc-macro2")]
+        if !proc_macro::is_available() {
+            tokens.extend(self.to_proc_macro2_tokens());
+            return;
+        }
+
         let mut ts = TokenStream::new();
         self.to_tokens(&mut ts);
         tokens.extend(proc_macro2::TokenStream::from(ts));
     }
 }
+
+/// Build the `{ #[deprecated(note = "...")] #[allow(dead_code)] const __W: () = (); let _ = __W;
+/// }` block for [`Warning::to_tokens`], spanned so the `deprecated` lint reports at `span`.
+///
+/// Generic over the token-stream [`Backend`] so the same construction logic renders through
+/// `proc_macro` (the default) or, on the `proc-macro2` fallback path, `proc_macro2`.
+fn deprecated_block<B: Backend>(message: &str, span: B::Span) -> B::TokenStream {
+    use backend::Delimiter;
+
+    let call_site = B::call_site();
+
+    let inner = B::concat([
+        B::punct('#', call_site),
+        B::group(
+            Delimiter::Bracket,
+            B::concat([
+                B::ident("deprecated", call_site),
+                B::group(
+                    Delimiter::Parenthesis,
+                    B::concat([
+                        B::ident("note", call_site),
+                        B::punct('=', call_site),
+                        B::literal_string(message, call_site),
+                    ]),
+                    call_site,
+                ),
+            ]),
+            call_site,
+        ),
+        B::punct('#', call_site),
+        B::group(
+            Delimiter::Bracket,
+            B::concat([
+                B::ident("allow", call_site),
+                B::group(
+                    Delimiter::Parenthesis,
+                    B::ident("dead_code", call_site),
+                    call_site,
+                ),
+            ]),
+            call_site,
+        ),
+        B::ident("const", call_site),
+        B::ident("__W", call_site),
+        B::punct(':', call_site),
+        B::group(Delimiter::Parenthesis, B::empty(), call_site),
+        B::punct('=', call_site),
+        B::group(Delimiter::Parenthesis, B::empty(), call_site),
+        B::punct(';', call_site),
+        B::ident("let", call_site),
+        B::ident("_", call_site),
+        B::punct('=', call_site),
+        B::ident("__W", span),
+        B::punct(';', call_site),
+    ]);
+
+    B::group(Delimiter::Brace, inner, call_site)
+}
+
+/// Convert a `syn::Error` into an `Error`, using the message and span of its first sub-error.
+///
+/// If `err` aggregates multiple sub-errors (see [`syn::Error::into_iter`]), only the first is
+/// kept; use [`Error::from_syn_errors`] instead to preserve them individually, each with its own
+/// span.
+#[cfg(feature = "syn")]
+impl From<syn::Error> for Error {
+    fn from(err: syn::Error) -> Self {
+        Error::new(err.to_string()).span(err.span())
+    }
+}
+
+#[cfg(feature = "syn")]
+impl Error {
+    /// Convert a `syn::Error` into one `Error` per aggregated sub-error (see
+    /// [`syn::Error::into_iter`]), each keeping its own message and span.
+    ///
+    /// This is a free-standing constructor, rather than a `From<syn::Error> for Vec<Error>` impl,
+    /// because both `syn::Error` and `Vec` are foreign types and the orphan rules forbid
+    /// implementing a foreign trait for a foreign type.
+    pub fn from_syn_errors(err: syn::Error) -> Vec<Error> {
+        err.into_iter()
+            .map(|err| Error::new(err.to_string()).span(err.span()))
+            .collect()
+    }
+}
+
+/// A `Result` alias for fallible operations whose error is an [`Error`].
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// A collection of [`Error`]s, gathered during a parse pass and emitted together.
+///
+/// Accumulating diagnostics and emitting them all at once — rather than aborting on the first
+/// failure, as returning an [`Error`] directly does — lets macro authors surface every problem
+/// the input has in a single invocation, instead of making the user fix one error, recompile, and
+/// discover the next.
+#[must_use = "this struct does nothing unless explicitly appended to a `TokenStream`"]
+#[derive(Debug, Clone, Default)]
+pub struct Diagnostics(Vec<Error>);
+
+impl Diagnostics {
+    /// Create an empty collection of diagnostics.
+    pub fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Add a single diagnostic to the collection.
+    pub fn push(&mut self, error: Error) {
+        self.0.push(error);
+    }
+
+    /// Add every diagnostic yielded by `errors` to the collection.
+    pub fn extend(&mut self, errors: impl IntoIterator<Item = Error>) {
+        self.0.extend(errors);
+    }
+
+    /// Returns `true` if the collection contains no diagnostics.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Append every contained diagnostic to the provided `TokenStream`.
+    pub fn to_tokens(&self, tokens: &mut TokenStream) {
+        for error in &self.0 {
+            error.to_tokens(tokens);
+        }
+    }
+
+    /// Consume the collection, returning a `TokenStream` containing every diagnostic.
+    pub fn into_token_stream(self) -> TokenStream {
+        let mut tokens = TokenStream::new();
+        self.to_tokens(&mut tokens);
+        tokens
+    }
+}
+
+#[cfg(feature = "quote")]
+impl quote::ToTokens for Diagnostics {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        for error in &self.0 {
+            quote::ToTokens::to_tokens(error, tokens);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod diagnostics_accumulator {
+        use super::*;
+
+        #[test]
+        fn tracks_emptiness_across_push_and_extend() {
+            let mut diagnostics = Diagnostics::new();
+            assert!(diagnostics.is_empty());
+
+            diagnostics.push(Error::new("first"));
+            assert!(!diagnostics.is_empty());
+
+            diagnostics.extend([Error::new("second"), Error::new("third")]);
+            assert!(!diagnostics.is_empty());
+        }
+    }
+
+    #[cfg(feature = "proc-macro2")]
+    mod proc_macro2_backend {
+        use super::*;
+
+        #[test]
+        fn error_renders_message_label_and_notes() {
+            let error = Error::new("something went wrong")
+                .label("right here")
+                .note("first note")
+                .note("second note");
+            let rendered = error.to_proc_macro2_tokens().to_string();
+
+            assert!(rendered.contains("on_unimplemented"));
+            assert!(rendered.contains("DiagnosticHack"));
+            assert!(rendered.contains("\"something went wrong\""));
+            assert!(rendered.contains("\"right here\""));
+            assert!(rendered.contains("\"first note\""));
+            assert!(rendered.contains("\"second note\""));
+        }
+
+        #[test]
+        fn error_span_note_emits_one_block_per_secondary_span() {
+            let error = Error::new("primary")
+                .span_note(proc_macro2::Span::call_site(), "first defined here")
+                .span_note(proc_macro2::Span::call_site(), "conflicts here");
+            let rendered = error.to_proc_macro2_tokens().to_string();
+
+            assert_eq!(rendered.matches("trait DiagnosticHack").count(), 3);
+            assert!(rendered.contains("\"first defined here\""));
+            assert!(rendered.contains("\"conflicts here\""));
+        }
+
+        #[test]
+        fn warning_renders_deprecated_block_with_message() {
+            let warning = Warning::new("heads up");
+            let rendered = warning.to_proc_macro2_tokens().to_string();
+
+            assert!(rendered.contains("deprecated"));
+            assert!(rendered.contains("\"heads up\""));
+            assert!(rendered.contains("__W"));
+        }
+
+        // `diagnostic_block`/`deprecated_block` are generic over `Backend` and emit the exact same
+        // token shape for `Native` and `ProcMacro2`; substring checks above don't catch malformed
+        // output (e.g. a dropped token breaking a turbofish), so also parse the rendered tokens as
+        // real Rust to make sure they're syntactically valid. `Error::to_proc_macro2_tokens` emits
+        // one standalone `{ ... }` block per span (primary, then one per secondary), so parse as a
+        // statement list rather than a single `syn::Block`.
+        #[cfg(feature = "syn")]
+        #[test]
+        fn error_tokens_parse_as_valid_statements() {
+            use syn::parse::Parser;
+
+            let error = Error::new("something went wrong")
+                .label("right here")
+                .note("a note")
+                .span_note(proc_macro2::Span::call_site(), "secondary");
+            let tokens = error.to_proc_macro2_tokens();
+
+            let stmts = syn::Block::parse_within
+                .parse2(tokens)
+                .expect("diagnostic_block must emit valid statements");
+            assert_eq!(
+                stmts.len(),
+                2,
+                "one block for the primary span, one for the secondary"
+            );
+        }
+
+        #[cfg(feature = "syn")]
+        #[test]
+        fn warning_tokens_parse_as_a_valid_block() {
+            let warning = Warning::new("heads up");
+            let tokens = warning.to_proc_macro2_tokens();
+
+            syn::parse2::<syn::Block>(tokens).expect("deprecated_block must emit a valid block");
+        }
+    }
+}